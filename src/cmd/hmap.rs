@@ -0,0 +1,129 @@
+use super::{extract_args, extract_bulk_string, MemoryBackend};
+use crate::{RespArray, RespError, RespFrame, RespNull};
+
+#[derive(Debug)]
+pub struct HGet {
+    key: String,
+    field: String,
+}
+
+#[derive(Debug)]
+pub struct HSet {
+    key: String,
+    field: String,
+    value: RespFrame,
+}
+
+#[derive(Debug)]
+pub struct HGetAll {
+    key: String,
+}
+
+impl TryFrom<Vec<RespFrame>> for HGet {
+    type Error = RespError;
+    fn try_from(args: Vec<RespFrame>) -> Result<Self, Self::Error> {
+        let mut args = extract_args(args, 2, "HGET")?.into_iter();
+        let key = extract_bulk_string(args.next().unwrap(), "HGET key")?;
+        let field = extract_bulk_string(args.next().unwrap(), "HGET field")?;
+        Ok(HGet {
+            key: String::from_utf8_lossy(&key).to_string(),
+            field: String::from_utf8_lossy(&field).to_string(),
+        })
+    }
+}
+
+impl TryFrom<Vec<RespFrame>> for HSet {
+    type Error = RespError;
+    fn try_from(args: Vec<RespFrame>) -> Result<Self, Self::Error> {
+        let mut args = extract_args(args, 3, "HSET")?.into_iter();
+        let key = extract_bulk_string(args.next().unwrap(), "HSET key")?;
+        let field = extract_bulk_string(args.next().unwrap(), "HSET field")?;
+        let value = args.next().unwrap();
+        Ok(HSet {
+            key: String::from_utf8_lossy(&key).to_string(),
+            field: String::from_utf8_lossy(&field).to_string(),
+            value,
+        })
+    }
+}
+
+impl TryFrom<Vec<RespFrame>> for HGetAll {
+    type Error = RespError;
+    fn try_from(args: Vec<RespFrame>) -> Result<Self, Self::Error> {
+        let mut args = extract_args(args, 1, "HGETALL")?.into_iter();
+        let key = extract_bulk_string(args.next().unwrap(), "HGETALL key")?;
+        Ok(HGetAll {
+            key: String::from_utf8_lossy(&key).to_string(),
+        })
+    }
+}
+
+impl HGet {
+    pub fn execute(self, backend: &MemoryBackend) -> RespFrame {
+        backend
+            .hget(&self.key, &self.field)
+            .unwrap_or(RespNull.into())
+    }
+}
+
+impl HSet {
+    pub fn execute(self, backend: &MemoryBackend) -> RespFrame {
+        backend.hset(self.key, self.field, self.value);
+        "OK".into()
+    }
+}
+
+impl HGetAll {
+    pub fn execute(self, backend: &MemoryBackend) -> RespFrame {
+        match backend.hgetall(&self.key) {
+            Some(entries) => {
+                let mut frames = Vec::with_capacity(entries.len() * 2);
+                for (field, value) in entries {
+                    frames.push(RespFrame::from(field.as_str()));
+                    frames.push(value);
+                }
+                RespArray::new(frames).into()
+            }
+            None => RespArray::new(vec![]).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BulkString;
+
+    #[test]
+    fn test_hget_hset() -> Result<(), RespError> {
+        let backend = MemoryBackend::new();
+        let cmd = HSet::try_from(vec![
+            BulkString::new("map").into(),
+            BulkString::new("field").into(),
+            BulkString::new("value").into(),
+        ])?;
+        cmd.execute(&backend);
+
+        let cmd = HGet::try_from(vec![
+            BulkString::new("map").into(),
+            BulkString::new("field").into(),
+        ])?;
+        let frame = cmd.execute(&backend);
+        assert_eq!(frame, BulkString::new("value").into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hgetall_missing_key() -> Result<(), RespError> {
+        let backend = MemoryBackend::new();
+        let cmd = HGetAll::try_from(vec![BulkString::new("missing").into()])?;
+        assert_eq!(cmd.execute(&backend), RespArray::new(vec![]).into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hset_wrong_arity() {
+        let ret = HSet::try_from(vec![BulkString::new("key").into()]);
+        assert!(ret.is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use super::{extract_args, extract_bulk_string, MemoryBackend};
+use crate::{RespError, RespFrame, RespNull};
+
+#[derive(Debug)]
+pub struct Get {
+    key: String,
+}
+
+#[derive(Debug)]
+pub struct Set {
+    key: String,
+    value: RespFrame,
+}
+
+impl TryFrom<Vec<RespFrame>> for Get {
+    type Error = RespError;
+    fn try_from(args: Vec<RespFrame>) -> Result<Self, Self::Error> {
+        let mut args = extract_args(args, 1, "GET")?.into_iter();
+        let key = extract_bulk_string(args.next().unwrap(), "GET key")?;
+        Ok(Get {
+            key: String::from_utf8_lossy(&key).to_string(),
+        })
+    }
+}
+
+impl TryFrom<Vec<RespFrame>> for Set {
+    type Error = RespError;
+    fn try_from(args: Vec<RespFrame>) -> Result<Self, Self::Error> {
+        let mut args = extract_args(args, 2, "SET")?.into_iter();
+        let key = extract_bulk_string(args.next().unwrap(), "SET key")?;
+        let value = args.next().unwrap();
+        Ok(Set {
+            key: String::from_utf8_lossy(&key).to_string(),
+            value,
+        })
+    }
+}
+
+impl Get {
+    pub fn execute(self, backend: &MemoryBackend) -> RespFrame {
+        backend.get(&self.key).unwrap_or(RespNull.into())
+    }
+}
+
+impl Set {
+    pub fn execute(self, backend: &MemoryBackend) -> RespFrame {
+        backend.set(self.key, self.value);
+        "OK".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BulkString, Command, RespArray};
+
+    #[test]
+    fn test_get_set() -> Result<(), RespError> {
+        let backend = MemoryBackend::new();
+        let cmd = Set::try_from(vec![
+            BulkString::new("key").into(),
+            BulkString::new("value").into(),
+        ])?;
+        cmd.execute(&backend);
+
+        let cmd = Get::try_from(vec![BulkString::new("key").into()])?;
+        let frame = cmd.execute(&backend);
+        assert_eq!(frame, BulkString::new("value").into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_missing_key() -> Result<(), RespError> {
+        let backend = MemoryBackend::new();
+        let cmd = Get::try_from(vec![BulkString::new("missing").into()])?;
+        assert_eq!(cmd.execute(&backend), RespNull.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_wrong_arity() {
+        let ret = Set::try_from(vec![BulkString::new("key").into()]);
+        assert!(ret.is_err());
+    }
+
+    #[test]
+    fn test_command_try_from_array() -> Result<(), RespError> {
+        let arr = RespArray::new(vec![
+            BulkString::new("SET").into(),
+            BulkString::new("key").into(),
+            BulkString::new("value").into(),
+        ]);
+        let cmd = Command::try_from(arr)?;
+        assert!(matches!(cmd, Command::Set(_)));
+        Ok(())
+    }
+}
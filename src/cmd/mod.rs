@@ -0,0 +1,137 @@
+mod hmap;
+mod map;
+
+use crate::{BulkString, RespArray, RespError, RespFrame};
+use dashmap::DashMap;
+use std::sync::Arc;
+
+pub use hmap::{HGet, HGetAll, HSet};
+pub use map::{Get, Set};
+
+/// A typed Redis command decoded from the wire form of a `RespArray`
+/// (e.g. `*2\r\n$3\r\nGET\r\n$3\r\nfoo\r\n`).
+#[derive(Debug)]
+pub enum Command {
+    Get(Get),
+    Set(Set),
+    HGet(HGet),
+    HSet(HSet),
+    HGetAll(HGetAll),
+}
+
+/// Runs a `Command` against a backend and produces the `RespFrame` reply.
+/// Alternative backends (a persistent store, a proxy) implement this trait
+/// instead of `MemoryBackend` to be dropped into the same server loop.
+pub trait CommandExecutor {
+    fn execute(&self, cmd: Command) -> RespFrame;
+}
+
+impl TryFrom<RespArray> for Command {
+    type Error = RespError;
+    fn try_from(arr: RespArray) -> Result<Self, Self::Error> {
+        let mut iter = arr.into_iter();
+        let cmd_name = match iter.next() {
+            Some(RespFrame::BulkString(cmd)) => cmd,
+            _ => {
+                return Err(RespError::InvalidFrame(
+                    "command array must start with a bulk string command name".to_string(),
+                ))
+            }
+        };
+        let args: Vec<RespFrame> = iter.collect();
+        match cmd_name.to_ascii_uppercase().as_slice() {
+            b"GET" => Ok(Command::Get(Get::try_from(args)?)),
+            b"SET" => Ok(Command::Set(Set::try_from(args)?)),
+            b"HGET" => Ok(Command::HGet(HGet::try_from(args)?)),
+            b"HSET" => Ok(Command::HSet(HSet::try_from(args)?)),
+            b"HGETALL" => Ok(Command::HGetAll(HGetAll::try_from(args)?)),
+            _ => Err(RespError::InvalidFrame(format!(
+                "unknown command: {:?}",
+                String::from_utf8_lossy(&cmd_name)
+            ))),
+        }
+    }
+}
+
+/// Pulls a fixed number of arguments out of a command's argument list,
+/// erroring with a descriptive message if the arity doesn't match.
+fn extract_args(
+    args: Vec<RespFrame>,
+    expected: usize,
+    name: &str,
+) -> Result<Vec<RespFrame>, RespError> {
+    if args.len() != expected {
+        return Err(RespError::InvalidFrame(format!(
+            "{} expects {} argument(s), got {}",
+            name,
+            expected,
+            args.len()
+        )));
+    }
+    Ok(args)
+}
+
+fn extract_bulk_string(frame: RespFrame, arg_name: &str) -> Result<BulkString, RespError> {
+    match frame {
+        RespFrame::BulkString(s) => Ok(s),
+        _ => Err(RespError::InvalidFrame(format!(
+            "{} must be a bulk string",
+            arg_name
+        ))),
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MemoryBackend(Arc<BackendInner>);
+
+#[derive(Debug, Default)]
+struct BackendInner {
+    map: DashMap<String, RespFrame>,
+    hmap: DashMap<String, DashMap<String, RespFrame>>,
+}
+
+impl MemoryBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<RespFrame> {
+        self.0.map.get(key).map(|v| v.value().clone())
+    }
+
+    pub fn set(&self, key: String, value: RespFrame) {
+        self.0.map.insert(key, value);
+    }
+
+    pub fn hget(&self, key: &str, field: &str) -> Option<RespFrame> {
+        self.0
+            .hmap
+            .get(key)
+            .and_then(|hmap| hmap.get(field).map(|v| v.value().clone()))
+    }
+
+    pub fn hset(&self, key: String, field: String, value: RespFrame) {
+        let hmap = self.0.hmap.entry(key).or_default();
+        hmap.insert(field, value);
+    }
+
+    pub fn hgetall(&self, key: &str) -> Option<Vec<(String, RespFrame)>> {
+        self.0.hmap.get(key).map(|hmap| {
+            hmap.iter()
+                .map(|entry| (entry.key().clone(), entry.value().clone()))
+                .collect()
+        })
+    }
+}
+
+impl CommandExecutor for MemoryBackend {
+    fn execute(&self, cmd: Command) -> RespFrame {
+        match cmd {
+            Command::Get(cmd) => cmd.execute(self),
+            Command::Set(cmd) => cmd.execute(self),
+            Command::HGet(cmd) => cmd.execute(self),
+            Command::HSet(cmd) => cmd.execute(self),
+            Command::HGetAll(cmd) => cmd.execute(self),
+        }
+    }
+}
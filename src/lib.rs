@@ -0,0 +1,5 @@
+mod cmd;
+mod resp;
+
+pub use cmd::*;
+pub use resp::*;
@@ -0,0 +1,682 @@
+use super::{
+    BulkError, BulkString, RespArray, RespBigNumber, RespDecode, RespError, RespFrame, RespMap,
+    RespNull, RespNullArray, RespNullBulkString, RespPush, RespSet, SimpleError, SimpleString,
+    VerbatimString,
+};
+use bytes::{Buf, BytesMut};
+use num_bigint::BigInt;
+
+const CRLF_LEN: usize = 2;
+
+impl RespDecode for RespFrame {
+    const PREFIX: &'static str = "";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let mut iter = buf.iter().peekable();
+        match iter.peek() {
+            Some(b'+') => Ok(SimpleString::decode(buf)?.into()),
+            Some(b'-') => Ok(SimpleError::decode(buf)?.into()),
+            Some(b':') => Ok(i64::decode(buf)?.into()),
+            Some(b'$') => match RespNullBulkString::decode(buf) {
+                Ok(frame) => Ok(frame.into()),
+                Err(RespError::NotComplete) => Err(RespError::NotComplete),
+                Err(_) => Ok(BulkString::decode(buf)?.into()),
+            },
+            Some(b'*') => match RespNullArray::decode(buf) {
+                Ok(frame) => Ok(frame.into()),
+                Err(RespError::NotComplete) => Err(RespError::NotComplete),
+                Err(_) => Ok(RespArray::decode(buf)?.into()),
+            },
+            Some(b'_') => Ok(RespNull::decode(buf)?.into()),
+            Some(b'#') => Ok(bool::decode(buf)?.into()),
+            Some(b',') => Ok(f64::decode(buf)?.into()),
+            Some(b'%') => Ok(RespMap::decode(buf)?.into()),
+            Some(b'~') => Ok(RespSet::decode(buf)?.into()),
+            Some(b'=') => Ok(VerbatimString::decode(buf)?.into()),
+            Some(b'!') => Ok(BulkError::decode(buf)?.into()),
+            Some(b'>') => Ok(RespPush::decode(buf)?.into()),
+            Some(b'(') => Ok(RespBigNumber::decode(buf)?.into()),
+            None => Err(RespError::NotComplete),
+            _ => Err(RespError::InvalidFrameType(format!(
+                "unknown frame type: {:?}",
+                buf
+            ))),
+        }
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        match buf.first() {
+            Some(b'+') => SimpleString::expect_length(buf),
+            Some(b'-') => SimpleError::expect_length(buf),
+            Some(b':') => i64::expect_length(buf),
+            Some(b'$') => match RespNullBulkString::expect_length(buf) {
+                Ok(len) => Ok(len),
+                Err(RespError::NotComplete) => Err(RespError::NotComplete),
+                Err(_) => BulkString::expect_length(buf),
+            },
+            Some(b'*') => match RespNullArray::expect_length(buf) {
+                Ok(len) => Ok(len),
+                Err(RespError::NotComplete) => Err(RespError::NotComplete),
+                Err(_) => RespArray::expect_length(buf),
+            },
+            Some(b'_') => RespNull::expect_length(buf),
+            Some(b'#') => bool::expect_length(buf),
+            Some(b',') => f64::expect_length(buf),
+            Some(b'%') => RespMap::expect_length(buf),
+            Some(b'~') => RespSet::expect_length(buf),
+            Some(b'=') => VerbatimString::expect_length(buf),
+            Some(b'!') => BulkError::expect_length(buf),
+            Some(b'>') => RespPush::expect_length(buf),
+            Some(b'(') => RespBigNumber::expect_length(buf),
+            None => Err(RespError::NotComplete),
+            _ => Err(RespError::InvalidFrameType(format!(
+                "unknown frame type: {:?}",
+                buf
+            ))),
+        }
+    }
+}
+
+impl RespDecode for SimpleString {
+    const PREFIX: &'static str = "+";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = std::str::from_utf8(&data[Self::PREFIX.len()..end])?;
+        Ok(SimpleString::new(s.to_string()))
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+impl RespDecode for SimpleError {
+    const PREFIX: &'static str = "-";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = std::str::from_utf8(&data[Self::PREFIX.len()..end])?;
+        Ok(SimpleError::new(s.to_string()))
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+impl RespDecode for i64 {
+    const PREFIX: &'static str = ":";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = std::str::from_utf8(&data[Self::PREFIX.len()..end])?;
+        Ok(s.parse()?)
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+impl RespDecode for BulkString {
+    const PREFIX: &'static str = "$";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let remained = &buf[end + CRLF_LEN..];
+        if remained.len() < len + CRLF_LEN {
+            return Err(RespError::NotComplete);
+        }
+        buf.advance(end + CRLF_LEN);
+        let data = buf.split_to(len + CRLF_LEN);
+        Ok(BulkString::new(data[..len].to_vec()))
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN + len + CRLF_LEN)
+    }
+}
+
+impl RespDecode for RespNullBulkString {
+    const PREFIX: &'static str = "$-1\r\n";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        extract_fixed_data(buf, "$-1\r\n", "NullBulkString")?;
+        Ok(RespNullBulkString)
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        if buf.len() < 5 {
+            return Err(RespError::NotComplete);
+        }
+        if buf.starts_with(b"$-1\r\n") {
+            Ok(5)
+        } else {
+            Err(RespError::InvalidFrameType(format!(
+                "expect: NullBulkString, got: {:?}",
+                buf
+            )))
+        }
+    }
+}
+
+impl RespDecode for RespArray {
+    const PREFIX: &'static str = "*";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let total = calc_total_length(buf, end, len, Self::PREFIX)?;
+        if buf.len() < total {
+            return Err(RespError::NotComplete);
+        }
+        buf.advance(end + CRLF_LEN);
+        let mut frames = Vec::with_capacity(len);
+        for _ in 0..len {
+            frames.push(RespFrame::decode(buf)?);
+        }
+        Ok(RespArray::new(frames))
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        calc_total_length(buf, end, len, Self::PREFIX)
+    }
+}
+
+impl RespDecode for RespNullArray {
+    const PREFIX: &'static str = "*-1\r\n";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        extract_fixed_data(buf, "*-1\r\n", "NullArray")?;
+        Ok(RespNullArray)
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        if buf.len() < 5 {
+            return Err(RespError::NotComplete);
+        }
+        if buf.starts_with(b"*-1\r\n") {
+            Ok(5)
+        } else {
+            Err(RespError::InvalidFrameType(format!(
+                "expect: NullArray, got: {:?}",
+                buf
+            )))
+        }
+    }
+}
+
+impl RespDecode for RespNull {
+    const PREFIX: &'static str = "_";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        extract_fixed_data(buf, "_\r\n", "Null")?;
+        Ok(RespNull)
+    }
+    fn expect_length(_buf: &[u8]) -> Result<usize, RespError> {
+        Ok(3)
+    }
+}
+
+impl RespDecode for bool {
+    const PREFIX: &'static str = "#";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        match extract_fixed_data(buf, "#t\r\n", "Bool") {
+            Ok(_) => Ok(true),
+            Err(RespError::NotComplete) => Err(RespError::NotComplete),
+            Err(_) => {
+                extract_fixed_data(buf, "#f\r\n", "Bool")?;
+                Ok(false)
+            }
+        }
+    }
+    fn expect_length(_buf: &[u8]) -> Result<usize, RespError> {
+        Ok(4)
+    }
+}
+
+impl RespDecode for f64 {
+    const PREFIX: &'static str = ",";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = std::str::from_utf8(&data[Self::PREFIX.len()..end])?;
+        match s {
+            "inf" | "+inf" => Ok(f64::INFINITY),
+            "-inf" => Ok(f64::NEG_INFINITY),
+            "nan" => Ok(f64::NAN),
+            _ => Ok(s.parse()?),
+        }
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+impl RespDecode for RespMap {
+    const PREFIX: &'static str = "%";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let total = calc_total_length(buf, end, len, Self::PREFIX)?;
+        if buf.len() < total {
+            return Err(RespError::NotComplete);
+        }
+        buf.advance(end + CRLF_LEN);
+        let mut map = RespMap::new();
+        for _ in 0..len {
+            let key = BulkString::decode(buf)?;
+            let value = RespFrame::decode(buf)?;
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        calc_total_length(buf, end, len, Self::PREFIX)
+    }
+}
+
+impl RespDecode for RespSet {
+    const PREFIX: &'static str = "~";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let total = calc_total_length(buf, end, len, Self::PREFIX)?;
+        if buf.len() < total {
+            return Err(RespError::NotComplete);
+        }
+        buf.advance(end + CRLF_LEN);
+        let mut frames = Vec::with_capacity(len);
+        for _ in 0..len {
+            frames.push(RespFrame::decode(buf)?);
+        }
+        Ok(RespSet::new(frames))
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        calc_total_length(buf, end, len, Self::PREFIX)
+    }
+}
+
+impl RespDecode for VerbatimString {
+    const PREFIX: &'static str = "=";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let remained = &buf[end + CRLF_LEN..];
+        if remained.len() < len + CRLF_LEN {
+            return Err(RespError::NotComplete);
+        }
+        if len < 4 || remained[3] != b':' {
+            return Err(RespError::InvalidFrame(format!(
+                "verbatim string missing format tag: {:?}",
+                remained
+            )));
+        }
+        buf.advance(end + CRLF_LEN);
+        let data = buf.split_to(len + CRLF_LEN);
+        let mut format = [0u8; 3];
+        format.copy_from_slice(&data[..3]);
+        Ok(VerbatimString::new(format, data[4..len].to_vec()))
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN + len + CRLF_LEN)
+    }
+}
+
+impl RespDecode for BulkError {
+    const PREFIX: &'static str = "!";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let remained = &buf[end + CRLF_LEN..];
+        if remained.len() < len + CRLF_LEN {
+            return Err(RespError::NotComplete);
+        }
+        buf.advance(end + CRLF_LEN);
+        let data = buf.split_to(len + CRLF_LEN);
+        Ok(BulkError::new(data[..len].to_vec()))
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN + len + CRLF_LEN)
+    }
+}
+
+impl RespDecode for RespPush {
+    const PREFIX: &'static str = ">";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let total = calc_total_length(buf, end, len, Self::PREFIX)?;
+        if buf.len() < total {
+            return Err(RespError::NotComplete);
+        }
+        buf.advance(end + CRLF_LEN);
+        let mut frames = Vec::with_capacity(len);
+        for _ in 0..len {
+            frames.push(RespFrame::decode(buf)?);
+        }
+        Ok(RespPush::new(frames))
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        calc_total_length(buf, end, len, Self::PREFIX)
+    }
+}
+
+impl RespDecode for RespBigNumber {
+    const PREFIX: &'static str = "(";
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = std::str::from_utf8(&data[Self::PREFIX.len()..end])?;
+        let n = BigInt::parse_bytes(s.as_bytes(), 10)
+            .ok_or_else(|| RespError::InvalidFrame(format!("invalid big number: {}", s)))?;
+        Ok(RespBigNumber::new(n))
+    }
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+// find nth CRLF in buf, used to locate the end of a simple (non-aggregate) frame
+fn find_crlf(buf: &[u8], nth: usize) -> Option<usize> {
+    let mut count = 0;
+    for i in 0..buf.len().saturating_sub(1) {
+        if buf[i] == b'\r' && buf[i + 1] == b'\n' {
+            count += 1;
+            if count == nth {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+// returns the index of the CRLF that terminates the frame's first line
+fn extract_simple_frame_data(buf: &[u8], prefix: &str) -> Result<usize, RespError> {
+    if buf.len() < prefix.len() + CRLF_LEN {
+        return Err(RespError::NotComplete);
+    }
+    if !buf.starts_with(prefix.as_bytes()) {
+        return Err(RespError::InvalidFrameType(format!(
+            "expect: {}, got: {:?}",
+            prefix, buf
+        )));
+    }
+    find_crlf(buf, 1).ok_or(RespError::NotComplete)
+}
+
+// checks that buf starts with an exact literal (e.g. "$-1\r\n", "_\r\n"), used for nulls/booleans
+fn extract_fixed_data(buf: &mut BytesMut, expect: &str, expect_type: &str) -> Result<(), RespError> {
+    if buf.len() < expect.len() {
+        return Err(RespError::NotComplete);
+    }
+    if !buf.starts_with(expect.as_bytes()) {
+        return Err(RespError::InvalidFrameType(format!(
+            "expect: {}, got: {:?}",
+            expect_type, buf
+        )));
+    }
+    buf.advance(expect.len());
+    Ok(())
+}
+
+// parses the `<prefix><len>\r\n` header common to bulk/aggregate frames
+fn parse_length(buf: &[u8], prefix: &str) -> Result<(usize, usize), RespError> {
+    let end = extract_simple_frame_data(buf, prefix)?;
+    let s = std::str::from_utf8(&buf[prefix.len()..end])?;
+    let len = s.parse()?;
+    Ok((end, len))
+}
+
+// returns the full byte length of an aggregate frame: its header line plus its child frames
+// (`len` frames, or `len` key/value pairs i.e. `2 * len` frames for a map)
+fn calc_total_length(buf: &[u8], end: usize, len: usize, prefix: &str) -> Result<usize, RespError> {
+    let mut total = end + CRLF_LEN;
+    let elements = if prefix == "%" { len * 2 } else { len };
+    for _ in 0..elements {
+        if total > buf.len() {
+            return Err(RespError::NotComplete);
+        }
+        let frame_len = RespFrame::expect_length(&buf[total..])?;
+        total += frame_len;
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_simple_string_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("+OK\r\n");
+        let frame = SimpleString::decode(&mut buf)?;
+        assert_eq!(frame, SimpleString::new("OK"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_simple_string_decode_not_complete() {
+        let mut buf = BytesMut::from("+OK\r");
+        let ret = SimpleString::decode(&mut buf);
+        assert_eq!(ret.unwrap_err(), RespError::NotComplete);
+    }
+
+    #[test]
+    fn test_simple_error_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("-Error message\r\n");
+        let frame = SimpleError::decode(&mut buf)?;
+        assert_eq!(frame, SimpleError::new("Error message"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_integer_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from(":+123\r\n");
+        let frame = i64::decode(&mut buf)?;
+        assert_eq!(frame, 123);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_string_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("$5\r\nHello\r\n");
+        let frame = BulkString::decode(&mut buf)?;
+        assert_eq!(frame, BulkString::new(b"Hello".to_vec()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_string_decode_not_complete() {
+        let mut buf = BytesMut::from("$5\r\nHel");
+        let ret = BulkString::decode(&mut buf);
+        assert_eq!(ret.unwrap_err(), RespError::NotComplete);
+    }
+
+    #[test]
+    fn test_null_bulk_string_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("$-1\r\n");
+        let frame = RespNullBulkString::decode(&mut buf)?;
+        assert_eq!(frame, RespNullBulkString);
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("*2\r\n$3\r\nSET\r\n$3\r\nfoo\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+        assert_eq!(
+            frame,
+            RespArray::new(vec![
+                BulkString::new("SET").into(),
+                BulkString::new("foo").into(),
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_decode_with_nested_null() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("*2\r\n$-1\r\n$3\r\nfoo\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+        assert_eq!(
+            frame,
+            RespArray::new(vec![RespNullBulkString.into(), BulkString::new("foo").into(),])
+        );
+
+        let mut buf = BytesMut::from("*2\r\n*-1\r\n$3\r\nfoo\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+        assert_eq!(
+            frame,
+            RespArray::new(vec![RespNullArray.into(), BulkString::new("foo").into(),])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_decode_not_complete() {
+        let mut buf = BytesMut::from("*2\r\n$3\r\nSET\r\n");
+        let ret = RespArray::decode(&mut buf);
+        assert_eq!(ret.unwrap_err(), RespError::NotComplete);
+    }
+
+    #[test]
+    fn test_null_array_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("*-1\r\n");
+        let frame = RespNullArray::decode(&mut buf)?;
+        assert_eq!(frame, RespNullArray);
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("_\r\n");
+        let frame = RespNull::decode(&mut buf)?;
+        assert_eq!(frame, RespNull);
+        Ok(())
+    }
+
+    #[test]
+    fn test_boolean_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("#t\r\n");
+        assert!(bool::decode(&mut buf)?);
+        let mut buf = BytesMut::from("#f\r\n");
+        assert!(!bool::decode(&mut buf)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from(",+1.23456\r\n");
+        let frame = f64::decode(&mut buf)?;
+        assert_eq!(frame, 1.23456);
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_decode_special_values() -> Result<(), RespError> {
+        let mut buf = BytesMut::from(",inf\r\n");
+        assert_eq!(f64::decode(&mut buf)?, f64::INFINITY);
+
+        let mut buf = BytesMut::from(",-inf\r\n");
+        assert_eq!(f64::decode(&mut buf)?, f64::NEG_INFINITY);
+
+        let mut buf = BytesMut::from(",nan\r\n");
+        assert!(f64::decode(&mut buf)?.is_nan());
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_round_trip_scientific() -> Result<(), RespError> {
+        use crate::RespEncode;
+        let frame: RespFrame = 1e8.into();
+        let mut buf = BytesMut::from(&frame.encode()[..]);
+        let decoded = f64::decode(&mut buf)?;
+        assert_eq!(decoded.to_bits(), 1e8_f64.to_bits());
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("%1\r\n$1\r\na\r\n:+1\r\n");
+        let frame = RespMap::decode(&mut buf)?;
+        let mut expected = RespMap::new();
+        expected.insert("a".to_string(), 1.into());
+        assert_eq!(frame, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_decode_binary_key() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("%1\r\n$4\r\na\r\nb\r\n:+1\r\n");
+        let frame = RespMap::decode(&mut buf)?;
+        let mut expected = RespMap::new();
+        expected.insert(BulkString::new(b"a\r\nb".to_vec()), 1.into());
+        assert_eq!(frame, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_decode_with_nested_null_value() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("%1\r\n$1\r\na\r\n$-1\r\n");
+        let frame = RespMap::decode(&mut buf)?;
+        let mut expected = RespMap::new();
+        expected.insert("a".to_string(), RespNullBulkString.into());
+        assert_eq!(frame, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("~2\r\n:+1\r\n:+2\r\n");
+        let frame = RespSet::decode(&mut buf)?;
+        assert_eq!(frame, RespSet::new(vec![1.into(), 2.into()]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_decode_with_nested_null() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("~2\r\n$-1\r\n:+2\r\n");
+        let frame = RespSet::decode(&mut buf)?;
+        assert_eq!(frame, RespSet::new(vec![RespNullBulkString.into(), 2.into()]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verbatim_string_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("=15\r\ntxt:Some string\r\n");
+        let frame = VerbatimString::decode(&mut buf)?;
+        assert_eq!(frame, VerbatimString::new(*b"txt", "Some string"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_error_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("!13\r\nError message\r\n");
+        let frame = BulkError::decode(&mut buf)?;
+        assert_eq!(frame, BulkError::new("Error message"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from(">2\r\n:+1\r\n:+2\r\n");
+        let frame = RespPush::decode(&mut buf)?;
+        assert_eq!(frame, RespPush::new(vec![1.into(), 2.into()]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_decode_with_nested_null() -> Result<(), RespError> {
+        let mut buf = BytesMut::from(">2\r\n*-1\r\n:+2\r\n");
+        let frame = RespPush::decode(&mut buf)?;
+        assert_eq!(frame, RespPush::new(vec![RespNullArray.into(), 2.into()]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_number_decode() -> Result<(), RespError> {
+        let mut buf = BytesMut::from("(1234567890123456789\r\n");
+        let frame = RespBigNumber::decode(&mut buf)?;
+        assert_eq!(
+            frame,
+            RespBigNumber::new(BigInt::from(1234567890123456789_i64))
+        );
+        Ok(())
+    }
+}
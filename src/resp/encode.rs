@@ -1,35 +1,54 @@
 use super::{
-    BulkString, RespArray, RespEncode, RespMap, RespNull, RespNullArray, RespNullBulkString,
-    RespSet, SimpleError, SimpleString,
+    BulkError, BulkString, RespArray, RespBigNumber, RespEncode, RespMap, RespNull, RespNullArray,
+    RespNullBulkString, RespPush, RespSet, SimpleError, SimpleString, VerbatimString,
 };
+use bytes::BytesMut;
 
 const BUF_CAP: usize = 4096;
 
 impl RespEncode for i64 {
     fn encode(self) -> Vec<u8> {
+        let mut buf = BytesMut::with_capacity(32);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
         let sign = if self < 0 { "" } else { "+" };
-        format!(":{}{}\r\n", sign, self).into_bytes()
+        buf.extend_from_slice(format!(":{}{}\r\n", sign, self).as_bytes());
     }
 }
 
 impl RespEncode for SimpleString {
     fn encode(self) -> Vec<u8> {
-        format!("+{}\r\n", self.0).into_bytes()
+        let mut buf = BytesMut::with_capacity(self.0.len() + 16);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(format!("+{}\r\n", self.0).as_bytes());
     }
 }
 impl RespEncode for SimpleError {
     fn encode(self) -> Vec<u8> {
-        format!("-{}\r\n", self.0).into_bytes()
+        let mut buf = BytesMut::with_capacity(self.0.len() + 16);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(format!("-{}\r\n", self.0).as_bytes());
     }
 }
 
 impl RespEncode for BulkString {
     fn encode(self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.len() + 16);
-        buf.extend_from_slice(&format!("${}\r\n", self.len()).into_bytes());
+        let mut buf = BytesMut::with_capacity(self.len() + 16);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(format!("${}\r\n", self.len()).as_bytes());
         buf.extend_from_slice(&self.0);
         buf.extend_from_slice(b"\r\n");
-        buf
     }
 }
 
@@ -37,22 +56,31 @@ impl RespEncode for RespNullBulkString {
     fn encode(self) -> Vec<u8> {
         b"$-1\r\n".to_vec()
     }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(b"$-1\r\n");
+    }
 }
 
 impl RespEncode for RespNull {
     fn encode(self) -> Vec<u8> {
         b"_\r\n".to_vec()
     }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(b"_\r\n");
+    }
 }
 
 impl RespEncode for RespArray {
     fn encode(self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(BUF_CAP);
-        buf.extend_from_slice(&format!("*{}\r\n", self.0.len()).into_bytes());
+        let mut buf = BytesMut::with_capacity(BUF_CAP);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(format!("*{}\r\n", self.0.len()).as_bytes());
         for frame in self.0 {
-            buf.extend_from_slice(&frame.encode());
+            frame.encode_into(buf);
         }
-        buf
     }
 }
 
@@ -60,48 +88,128 @@ impl RespEncode for RespNullArray {
     fn encode(self) -> Vec<u8> {
         b"*-1\r\n".to_vec()
     }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(b"*-1\r\n");
+    }
 }
 
 impl RespEncode for bool {
     fn encode(self) -> Vec<u8> {
-        format!("#{}\r\n", if self { "t" } else { "f" }).into_bytes()
+        let mut buf = BytesMut::with_capacity(8);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(if self { b"#t\r\n" } else { b"#f\r\n" });
     }
 }
 
 impl RespEncode for f64 {
     fn encode(self) -> Vec<u8> {
-        // format!(",{:+e}\r\n",self).into_bytes()
-        let mut buf = Vec::with_capacity(64);
+        let mut buf = BytesMut::with_capacity(64);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        if self.is_nan() {
+            buf.extend_from_slice(b",nan\r\n");
+            return;
+        }
+        if self.is_infinite() {
+            buf.extend_from_slice(if self > 0.0 { b",inf\r\n" } else { b",-inf\r\n" });
+            return;
+        }
         let ret = if self.abs() >= 1e+8 {
-            format!(",{:e}\r\n", self)
+            let sign = if self < 0.0 { "" } else { "+" };
+            format!(",{}{:e}\r\n", sign, self)
         } else {
             let sign = if self < 0.0 { "" } else { "+" };
             format!(",{}{}\r\n", sign, self)
         };
-        buf.extend_from_slice(&ret.into_bytes());
-        buf
+        buf.extend_from_slice(ret.as_bytes());
     }
 }
 
 impl RespEncode for RespMap {
     fn encode(self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(BUF_CAP);
-        buf.extend_from_slice(&format!("%{}\r\n", self.0.len()).into_bytes());
+        let mut buf = BytesMut::with_capacity(BUF_CAP);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(format!("%{}\r\n", self.0.len()).as_bytes());
         for (k, v) in self.0 {
-            buf.extend_from_slice(&SimpleString::new(k).encode());
-            buf.extend_from_slice(&v.encode());
+            k.encode_into(buf);
+            v.encode_into(buf);
         }
-        buf
     }
 }
 impl RespEncode for RespSet {
     fn encode(self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(BUF_CAP);
-        buf.extend_from_slice(&format!("~{}\r\n", self.0.len()).into_bytes());
+        let mut buf = BytesMut::with_capacity(BUF_CAP);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(format!("~{}\r\n", self.0.len()).as_bytes());
+        for frame in self.0 {
+            frame.encode_into(buf);
+        }
+    }
+}
+
+impl RespEncode for VerbatimString {
+    fn encode(self) -> Vec<u8> {
+        let payload_len = self.format.len() + 1 + self.data.len();
+        let mut buf = BytesMut::with_capacity(payload_len + 16);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        let payload_len = self.format.len() + 1 + self.data.len();
+        buf.extend_from_slice(format!("={}\r\n", payload_len).as_bytes());
+        buf.extend_from_slice(&self.format);
+        buf.extend_from_slice(b":");
+        buf.extend_from_slice(&self.data);
+        buf.extend_from_slice(b"\r\n");
+    }
+}
+
+impl RespEncode for BulkError {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = BytesMut::with_capacity(self.len() + 16);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(format!("!{}\r\n", self.len()).as_bytes());
+        buf.extend_from_slice(&self.0);
+        buf.extend_from_slice(b"\r\n");
+    }
+}
+
+impl RespEncode for RespPush {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = BytesMut::with_capacity(BUF_CAP);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(format!(">{}\r\n", self.0.len()).as_bytes());
         for frame in self.0 {
-            buf.extend_from_slice(&frame.encode());
+            frame.encode_into(buf);
         }
-        buf
+    }
+}
+
+impl RespEncode for RespBigNumber {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = BytesMut::with_capacity(64);
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+    fn encode_into(self, buf: &mut BytesMut) {
+        buf.extend_from_slice(format!("({}\r\n", self.0).as_bytes());
     }
 }
 
@@ -111,6 +219,7 @@ mod tests {
     use crate::RespFrame;
 
     use super::*;
+    use num_bigint::BigInt;
     #[test]
     fn test_simple_string_encode() {
         let frame: RespFrame = SimpleString::new("OK".to_string()).into();
@@ -167,17 +276,28 @@ mod tests {
         let frame: RespFrame = (-123.456).into();
         assert_eq!(frame.encode(), b",-123.456\r\n");
         let frame: RespFrame = 1e+8.into();
-        assert_eq!(frame.encode(), b",1e8\r\n");
+        assert_eq!(frame.encode(), b",+1e8\r\n");
         let frame: RespFrame = (-1e+8).into();
         assert_eq!(frame.encode(), b",-1e8\r\n");
     }
     #[test]
+    fn test_double_encode_special_values() {
+        let frame: RespFrame = f64::INFINITY.into();
+        assert_eq!(frame.encode(), b",inf\r\n");
+        let frame: RespFrame = f64::NEG_INFINITY.into();
+        assert_eq!(frame.encode(), b",-inf\r\n");
+        let frame: RespFrame = f64::NAN.into();
+        assert_eq!(frame.encode(), b",nan\r\n");
+    }
+    #[test]
     fn test_map_encode() {
         let mut frame = RespMap::new();
         frame.insert("a".to_string(), (1).into());
         frame.insert("b".to_string(), (1234.567).into());
-        // assert_eq!(String::from_utf8_lossy(&frame.encode()),"%2\r\n+a\r\n:+1\r\n+b\r\n,+1234.567\r\n");
-        assert_eq!(frame.encode(), b"%2\r\n+a\r\n:+1\r\n+b\r\n,+1234.567\r\n");
+        assert_eq!(
+            frame.encode(),
+            b"%2\r\n$1\r\na\r\n:+1\r\n$1\r\nb\r\n,+1234.567\r\n".to_vec()
+        );
     }
     #[test]
     fn test_set_encode() {
@@ -185,4 +305,49 @@ mod tests {
         // assert_eq!(String::from_utf8_lossy(&frame.encode()),"~3\r\n:+1\r\n:+2\r\n:+3\r\n");
         assert_eq!(frame.encode(), b"~3\r\n:+1\r\n:+2\r\n:+3\r\n");
     }
+    #[test]
+    fn test_verbatim_string_encode() {
+        let frame: RespFrame = VerbatimString::new(*b"txt", "Some string").into();
+        assert_eq!(frame.encode(), b"=15\r\ntxt:Some string\r\n");
+    }
+    #[test]
+    fn test_bulk_error_encode() {
+        let frame: RespFrame = BulkError::new("Error message").into();
+        assert_eq!(frame.encode(), b"!13\r\nError message\r\n");
+    }
+    #[test]
+    fn test_push_encode() {
+        let frame: RespFrame = RespPush::new(vec![1.into(), 2.into()]).into();
+        assert_eq!(frame.encode(), b">2\r\n:+1\r\n:+2\r\n");
+    }
+    #[test]
+    fn test_big_number_encode() {
+        let frame: RespFrame = RespBigNumber::new(BigInt::from(1234567890123456789_i64)).into();
+        assert_eq!(frame.encode(), b"(1234567890123456789\r\n");
+    }
+    #[test]
+    fn test_encode_into_appends_to_existing_buffer() {
+        let mut buf = BytesMut::from(&b"prefix"[..]);
+        let frame: RespFrame = SimpleString::new("OK").into();
+        frame.encode_into(&mut buf);
+        assert_eq!(&buf[..], b"prefix+OK\r\n".as_slice());
+    }
+    #[test]
+    fn test_nested_array_encode_into_matches_encode() {
+        let frame: RespFrame = RespArray::new(vec![
+            RespArray::new(vec![1.into(), 2.into()]).into(),
+            BulkString::new("foo").into(),
+        ])
+        .into();
+        let expected = b"*2\r\n*2\r\n:+1\r\n:+2\r\n$3\r\nfoo\r\n".to_vec();
+        let mut buf = BytesMut::new();
+        let frame2: RespFrame = RespArray::new(vec![
+            RespArray::new(vec![1.into(), 2.into()]).into(),
+            BulkString::new("foo").into(),
+        ])
+        .into();
+        frame2.encode_into(&mut buf);
+        assert_eq!(frame.encode(), expected);
+        assert_eq!(&buf[..], expected.as_slice());
+    }
 }
@@ -1,8 +1,12 @@
 mod decode;
 mod encode;
+mod stream;
+
+pub use stream::Decoder;
 
 use bytes::BytesMut;
 use enum_dispatch::enum_dispatch;
+use num_bigint::BigInt;
 use std::collections::BTreeMap;
 use std::ops::{Deref, DerefMut};
 use thiserror::Error;
@@ -26,7 +30,7 @@ Pushes	RESP3	Aggregate	>
 
 // enum_dispatch 为每一个变体添加派生trait，包括了Into Trait，它可以将一个类型转换为另一个类型。
 #[enum_dispatch(RespEncode)]
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum RespFrame {
     SimpleString(SimpleString),
     Error(SimpleError),
@@ -40,6 +44,10 @@ pub enum RespFrame {
     Double(f64),
     Map(RespMap),
     Set(RespSet),
+    VerbatimString(VerbatimString),
+    BulkError(BulkError),
+    Push(RespPush),
+    BigNumber(RespBigNumber),
 }
 
 #[derive(Debug, Error, PartialEq, Eq)]
@@ -52,6 +60,8 @@ pub enum RespError {
     InvalidFrameLength(isize),
     #[error("Frame is not complete")]
     NotComplete,
+    #[error("Unexpected end of stream")]
+    Eof,
 
     #[error("Parse error: {0}")]
     ParseIntError(#[from] std::num::ParseIntError),
@@ -59,42 +69,69 @@ pub enum RespError {
     Utf8Error(#[from] std::str::Utf8Error),
     #[error("Parse float error: {0}")]
     ParseFloatError(#[from] std::num::ParseFloatError),
+    #[error("IO error: {0}")]
+    Io(String),
+}
+
+impl From<std::io::Error> for RespError {
+    fn from(e: std::io::Error) -> Self {
+        RespError::Io(e.to_string())
+    }
 }
 #[enum_dispatch]
 pub trait RespEncode {
     fn encode(self) -> Vec<u8>;
+    /// Appends the wire form directly onto `buf` instead of allocating an
+    /// intermediate `Vec`, so nested aggregates write straight into the
+    /// caller's buffer rather than building and copying a `Vec` per child.
+    fn encode_into(self, buf: &mut BytesMut);
 }
 pub trait RespDecode: Sized {
     const PREFIX: &'static str;
     fn decode(buf: &mut BytesMut) -> Result<Self, RespError>;
     fn expect_length(buf: &[u8]) -> Result<usize, RespError>;
 }
-#[derive(Debug, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
 pub struct SimpleString(String);
 
-#[derive(Debug, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
 pub struct SimpleError(String);
 
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct RespNull;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
 pub struct RespNullArray;
 
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct RespNullBulkString;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct BulkString(Vec<u8>);
 
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct RespArray(Vec<RespFrame>);
 
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct RespSet(Vec<RespFrame>);
 
-#[derive(Debug, PartialEq, PartialOrd)]
-pub struct RespMap(BTreeMap<String, RespFrame>);
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct RespMap(BTreeMap<BulkString, RespFrame>);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
+pub struct VerbatimString {
+    format: [u8; 3],
+    data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
+pub struct BulkError(Vec<u8>);
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct RespPush(Vec<RespFrame>);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
+pub struct RespBigNumber(BigInt);
 impl Deref for SimpleString {
     type Target = String;
     fn deref(&self) -> &Self::Target {
@@ -119,8 +156,15 @@ impl Deref for RespArray {
         &self.0
     }
 }
+impl IntoIterator for RespArray {
+    type Item = RespFrame;
+    type IntoIter = std::vec::IntoIter<RespFrame>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
 impl Deref for RespMap {
-    type Target = BTreeMap<String, RespFrame>;
+    type Target = BTreeMap<BulkString, RespFrame>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -131,6 +175,24 @@ impl Deref for RespSet {
         &self.0
     }
 }
+impl Deref for BulkError {
+    type Target = Vec<u8>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl Deref for RespPush {
+    type Target = Vec<RespFrame>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl Deref for RespBigNumber {
+    type Target = BigInt;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 impl DerefMut for RespMap {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
@@ -160,6 +222,12 @@ impl RespMap {
     pub fn new() -> Self {
         RespMap(BTreeMap::new())
     }
+
+    /// Inserts a key, accepting anything that converts to a binary-safe
+    /// `BulkString` (e.g. `&str`) so `&str`-keyed call sites keep working.
+    pub fn insert(&mut self, key: impl Into<BulkString>, value: RespFrame) -> Option<RespFrame> {
+        self.0.insert(key.into(), value)
+    }
 }
 impl Default for RespMap {
     fn default() -> Self {
@@ -171,6 +239,29 @@ impl RespSet {
         RespSet(s.into())
     }
 }
+impl VerbatimString {
+    pub fn new(format: [u8; 3], data: impl Into<Vec<u8>>) -> Self {
+        VerbatimString {
+            format,
+            data: data.into(),
+        }
+    }
+}
+impl BulkError {
+    pub fn new(s: impl Into<Vec<u8>>) -> Self {
+        BulkError(s.into())
+    }
+}
+impl RespPush {
+    pub fn new(s: impl Into<Vec<RespFrame>>) -> Self {
+        RespPush(s.into())
+    }
+}
+impl RespBigNumber {
+    pub fn new(n: impl Into<BigInt>) -> Self {
+        RespBigNumber(n.into())
+    }
+}
 
 impl From<&str> for SimpleString {
     fn from(s: &str) -> Self {
@@ -196,6 +287,12 @@ impl From<&str> for BulkString {
     }
 }
 
+impl From<String> for BulkString {
+    fn from(s: String) -> Self {
+        BulkString(s.into_bytes())
+    }
+}
+
 impl From<&[u8]> for BulkString {
     fn from(s: &[u8]) -> Self {
         BulkString(s.to_vec())
@@ -219,3 +316,15 @@ impl<const N: usize> From<&[u8; N]> for RespFrame {
         BulkString(s.to_vec()).into()
     }
 }
+
+impl From<&str> for BulkError {
+    fn from(s: &str) -> Self {
+        BulkError(s.as_bytes().to_vec())
+    }
+}
+
+impl From<BigInt> for RespBigNumber {
+    fn from(n: BigInt) -> Self {
+        RespBigNumber(n)
+    }
+}
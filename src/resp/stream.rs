@@ -0,0 +1,145 @@
+use super::{RespDecode, RespError, RespFrame};
+use bytes::BytesMut;
+use std::io::Read;
+
+const BUF_INIT_CAP: usize = 4096;
+
+/// Decodes a stream of RESP frames off any `std::io::Read`, buffering
+/// internally so a frame split across several `read` calls (e.g. a large
+/// array arriving over several TCP segments) is still assembled correctly.
+pub struct Decoder<R> {
+    reader: R,
+    buf: BytesMut,
+    done: bool,
+}
+
+impl<R: Read> Decoder<R> {
+    pub fn new(reader: R) -> Self {
+        Decoder {
+            reader,
+            buf: BytesMut::with_capacity(BUF_INIT_CAP),
+            done: false,
+        }
+    }
+
+    /// Reads the next frame, pulling more bytes from the reader as needed.
+    /// Returns `Ok(None)` on a clean end-of-stream (no partial frame
+    /// pending), and `RespError::Eof` if the stream ends mid-frame.
+    pub fn read_frame(&mut self) -> Result<Option<RespFrame>, RespError> {
+        loop {
+            match RespFrame::decode(&mut self.buf) {
+                Ok(frame) => return Ok(Some(frame)),
+                Err(RespError::NotComplete) => {
+                    if !self.fill_buf()? {
+                        return if self.buf.is_empty() {
+                            Ok(None)
+                        } else {
+                            Err(RespError::Eof)
+                        };
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn fill_buf(&mut self) -> Result<bool, RespError> {
+        let mut chunk = [0u8; BUF_INIT_CAP];
+        let n = loop {
+            match self.reader.read(&mut chunk) {
+                Ok(n) => break n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            }
+        };
+        if n == 0 {
+            return Ok(false);
+        }
+        self.buf.extend_from_slice(&chunk[..n]);
+        Ok(true)
+    }
+}
+
+impl<R: Read> Iterator for Decoder<R> {
+    type Item = Result<RespFrame, RespError>;
+    /// Fused: once a clean EOF or an error (e.g. a truncated frame) is
+    /// reached, every subsequent call returns `None` instead of repeating
+    /// the same outcome on the leftover, un-decodable buffer contents.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.read_frame() {
+            Ok(Some(frame)) => Some(Ok(frame)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                self.buf.clear();
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BulkString, RespArray};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_read_frame() -> Result<(), RespError> {
+        let data = b"$5\r\nHello\r\n".to_vec();
+        let mut decoder = Decoder::new(Cursor::new(data));
+        let frame = decoder.read_frame()?.unwrap();
+        assert_eq!(frame, BulkString::new("Hello").into());
+        assert!(decoder.read_frame()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_frame_chunked() -> Result<(), RespError> {
+        struct Chunked(Vec<u8>);
+        impl Read for Chunked {
+            fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+                if self.0.is_empty() {
+                    return Ok(0);
+                }
+                out[0] = self.0.remove(0);
+                Ok(1)
+            }
+        }
+        let data = b"*2\r\n$3\r\nfoo\r\n$3\r\nbar\r\n".to_vec();
+        let mut decoder = Decoder::new(Chunked(data));
+        let frame = decoder.read_frame()?.unwrap();
+        assert_eq!(
+            frame,
+            RespArray::new(vec![
+                BulkString::new("foo").into(),
+                BulkString::new("bar").into(),
+            ])
+            .into()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_frame_truncated_stream() {
+        let data = b"$5\r\nHel".to_vec();
+        let mut decoder = Decoder::new(Cursor::new(data));
+        let err = decoder.read_frame().unwrap_err();
+        assert_eq!(err, RespError::Eof);
+    }
+
+    #[test]
+    fn test_iterator_stops_after_truncated_stream() {
+        let data = b"$5\r\nHel".to_vec();
+        let decoder = Decoder::new(Cursor::new(data));
+        let frames: Vec<_> = decoder.collect();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0], Err(RespError::Eof));
+    }
+}